@@ -4,6 +4,10 @@ pub struct Unchanged;
 /// This type is not widely used. It will be removed in 0.3.0.
 pub struct Removed;
 
+/// This type's name is misspelled. The corrected spelling is used starting
+/// in 0.3.0.
+pub struct Mispeled;
+
 /// This module contains a type that will be moved to a different module in
 /// 0.3.0.
 pub mod before {
@@ -1,12 +1,75 @@
 extern crate semver_trick;
 
-pub use semver_trick::Unchanged;
+/// Generates the re-export glue that reconstructs an old major version's
+/// public path set on top of the new crate. Each arm produces exactly the
+/// `pub use` statement (and wrapping module, if any) that the glue would
+/// otherwise have to be written by hand.
+///
+/// ```ignore
+/// semver_trick! {
+///     reexport semver_trick::Unchanged;
+///     moved before::Moved => semver_trick::after::Moved;
+///     renamed Mispeled => semver_trick::Corrected;
+/// }
+/// ```
+#[cfg_attr(doc, allow(unused_macros))]
+macro_rules! semver_trick {
+    () => {};
+    (reexport $path:path; $($rest:tt)*) => {
+        pub use $path;
+        semver_trick! { $($rest)* }
+    };
+    (moved $old_mod:ident :: $old_item:ident => $($new:tt)::+ ; $($rest:tt)*) => {
+        pub mod $old_mod {
+            pub use $($new)::+ as $old_item;
+        }
+        semver_trick! { $($rest)* }
+    };
+    (renamed $old:ident => $($new:tt)::+ ; $($rest:tt)*) => {
+        // `#[deprecated]` does not currently lint on a renaming `pub use`,
+        // but a `pub use` (unlike a type alias) is the only thing that
+        // keeps `$old` usable the same way the original item was --
+        // constructible, matchable, callable -- so it's kept over a
+        // type-only alias despite the lint not firing.
+        #[doc(hidden)]
+        #[deprecated]
+        pub use $($new)::+ as $old;
+        semver_trick! { $($rest)* }
+    };
+}
+
+#[cfg(not(doc))]
+semver_trick! {
+    reexport semver_trick::Unchanged;
+    moved before::Moved => semver_trick::after::Moved;
+    renamed Mispeled => semver_trick::Corrected;
+}
 
 /// This type is not widely used. It will be removed in 0.3.0.
 pub struct Removed;
 
+// docs.rs renders rustdoc from this crate's own source, which has nothing
+// to show for items re-exported from `semver_trick`. Swap in readable,
+// link-annotated placeholders for doc builds so the page isn't blank.
+#[cfg(doc)]
+mod doc {
+    pub enum NotDefinedHere {}
+}
+
+/// See [`semver_trick::Unchanged`].
+#[cfg(doc)]
+pub type Unchanged = doc::NotDefinedHere;
+
 /// This module contains a type that will be moved to a different module in
 /// 0.3.0.
+#[cfg(doc)]
 pub mod before {
-    pub use semver_trick::after::Moved;
+    /// See [`semver_trick::after::Moved`].
+    pub type Moved = crate::doc::NotDefinedHere;
 }
+
+/// See [`semver_trick::Corrected`].
+#[doc(hidden)]
+#[deprecated]
+#[cfg(doc)]
+pub type Mispeled = doc::NotDefinedHere;
@@ -4,6 +4,9 @@ pub struct Unchanged;
 /// This type has been added in 0.3.0.
 pub struct Added;
 
+/// This type was previously named `Mispeled`, a typo corrected in 0.3.0.
+pub struct Corrected;
+
 /// This module contains a type that was previously in a different module.
 pub mod after {
     /// This type will be moved to a different module in 0.3.0.
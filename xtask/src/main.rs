@@ -0,0 +1,116 @@
+//! Walks the public items declared across the version modules in this
+//! repository and emits `semver.md`, a line-per-item classification of
+//! each one as `BREAKING` or `MODIFIED`. An item is `BREAKING` if its old
+//! path no longer resolves to anything; otherwise it's `MODIFIED`, since a
+//! working re-export keeps downstream code compiling even though the item
+//! moved, was renamed, or is brand new.
+//!
+//! Nothing here is a hand-maintained list: the old and new item sets are
+//! scraped straight out of the version crates' `lib.rs` files, and the
+//! re-export mapping is scraped out of the bridging crate's
+//! `semver_trick!` invocation, so an item added to one side without a
+//! matching update to the other shows up the next time this runs.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CRATE_NAME: &str = "semver_trick";
+const OLD_VERSION_DIR: &str = "semver-trick-0.2.0";
+const NEW_VERSION_DIR: &str = "semver-trick-0.3.0";
+const BRIDGE_VERSION_DIR: &str = "semver-trick-0.2.1";
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn read_lib_rs(version_dir: &str) -> String {
+    let path = workspace_root().join(version_dir).join("src/lib.rs");
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err))
+}
+
+/// Scans a version crate's `lib.rs` for `pub struct` items, returning their
+/// fully qualified paths (including any `pub mod` nesting).
+fn public_item_paths(version_dir: &str) -> Vec<String> {
+    let source = read_lib_rs(version_dir);
+
+    let mut items = Vec::new();
+    let mut mod_stack: Vec<&str> = Vec::new();
+    for line in source.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("pub mod ") {
+            mod_stack.push(rest.trim_end_matches('{').trim());
+        } else if line == "}" {
+            mod_stack.pop();
+        } else if let Some(name) = line
+            .strip_prefix("pub struct ")
+            .and_then(|rest| rest.strip_suffix(';'))
+        {
+            let mut path = vec![CRATE_NAME];
+            path.extend(mod_stack.iter().copied());
+            path.push(name.trim());
+            items.push(path.join("::"));
+        }
+    }
+    items
+}
+
+/// Scans the bridging crate's `semver_trick!` invocation for its
+/// `reexport`/`moved`/`renamed` declarations, returning a map from each
+/// item's old path to the path it's bridged to in the new crate.
+fn reexport_map() -> HashMap<String, String> {
+    let source = read_lib_rs(BRIDGE_VERSION_DIR);
+
+    let mut map = HashMap::new();
+    for line in source.lines() {
+        let line = line.trim().trim_end_matches(';').trim();
+        let entry = line
+            .strip_prefix("reexport ")
+            .map(|path| (path, path))
+            .or_else(|| {
+                let rest = line.strip_prefix("moved ").or_else(|| line.strip_prefix("renamed "))?;
+                let (old, new) = rest.split_once("=>")?;
+                Some((old.trim(), new.trim()))
+            });
+        if let Some((old, new)) = entry {
+            let old_path = if old.starts_with(CRATE_NAME) {
+                old.to_string()
+            } else {
+                format!("{CRATE_NAME}::{old}")
+            };
+            map.insert(old_path, new.to_string());
+        }
+    }
+    map
+}
+
+fn classify_old_item(old_path: &str, new_items: &[String], bridged: &HashMap<String, String>) -> String {
+    if new_items.iter().any(|new_path| new_path == old_path) {
+        format!("MODIFIED: `{old_path}` is unchanged.")
+    } else if let Some(new_path) = bridged.get(old_path) {
+        format!("MODIFIED: `{old_path}` re-exported at its old path, now defined at `{new_path}`.")
+    } else {
+        format!("BREAKING: `{old_path}` was removed.")
+    }
+}
+
+fn main() {
+    let old_items = public_item_paths(OLD_VERSION_DIR);
+    let new_items = public_item_paths(NEW_VERSION_DIR);
+    let bridged = reexport_map();
+
+    let mut semver_md = String::new();
+    for old_path in &old_items {
+        writeln!(semver_md, "{}", classify_old_item(old_path, &new_items, &bridged)).unwrap();
+    }
+    for new_path in &new_items {
+        let is_old_path = old_items.iter().any(|old| old == new_path);
+        let is_bridge_target = bridged.values().any(|target| target == new_path);
+        if !is_old_path && !is_bridge_target {
+            writeln!(semver_md, "MODIFIED: new type `{new_path}`.").unwrap();
+        }
+    }
+
+    fs::write("semver.md", semver_md).expect("failed to write semver.md");
+}